@@ -0,0 +1,119 @@
+use crate::{clone_box, DynClone};
+use alloc::boxed::Box;
+use core::any::Any;
+
+/// A trait object that is both clonable and downcastable.
+///
+/// `Box<dyn DynAny>` behaves like a `Box<dyn Any>` that additionally
+/// implements [`std::clone::Clone`], which the standard library's
+/// `Box<dyn Any>` does not. Any type that is `'static` and implements
+/// `std::clone::Clone` automatically implements `DynAny`, so heterogeneous
+/// type-erased values can be kept in a collection and still be deep-cloned —
+/// the motivating use case being a clonable type-map.
+///
+/// ```
+/// use dyn_clone::DynAny;
+///
+/// let values: Vec<Box<dyn DynAny>> = vec![Box::new(1u32), Box::new("s")];
+/// let copy = values.clone();
+///
+/// assert_eq!(copy[0].downcast_ref::<u32>(), Some(&1));
+/// ```
+///
+/// [`std::clone::Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+pub trait DynAny: DynClone {
+    // Not public API
+    #[doc(hidden)]
+    fn as_any(&self) -> &dyn Any;
+
+    // Not public API
+    #[doc(hidden)]
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T> DynAny for T
+where
+    T: Any + Clone,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl dyn DynAny {
+    /// Returns a reference to the inner value if it is of type `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Returns a mutable reference to the inner value if it is of type `T`.
+    pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.as_any_mut().downcast_mut()
+    }
+
+    /// Attempts to downcast the box to a concrete type, returning the original
+    /// box on failure.
+    pub fn downcast<T: Any>(self: Box<Self>) -> Result<Box<T>, Box<dyn DynAny>> {
+        let this: &dyn DynAny = &*self;
+        if this.as_any().is::<T>() {
+            let raw = Box::into_raw(self);
+            unsafe { Ok(Box::from_raw(raw as *mut T)) }
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Clone for Box<dyn DynAny> {
+    fn clone(&self) -> Self {
+        clone_box(&**self)
+    }
+}
+
+impl Clone for Box<dyn DynAny + Send> {
+    fn clone(&self) -> Self {
+        clone_box(&**self)
+    }
+}
+
+impl Clone for Box<dyn DynAny + Sync> {
+    fn clone(&self) -> Self {
+        clone_box(&**self)
+    }
+}
+
+impl Clone for Box<dyn DynAny + Send + Sync> {
+    fn clone(&self) -> Self {
+        clone_box(&**self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DynAny;
+    use alloc::boxed::Box;
+    use alloc::string::String;
+
+    #[test]
+    fn test_clone_and_downcast() {
+        let value: Box<dyn DynAny> = Box::new(String::from("hello"));
+        let clone = value.clone();
+
+        assert_eq!(value.downcast_ref::<String>(), Some(&String::from("hello")));
+        assert!(matches!(clone.downcast::<String>().as_deref(), Ok(s) if s == "hello"));
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_clone<T: Clone>() {}
+
+        assert_clone::<Box<dyn DynAny>>();
+        assert_clone::<Box<dyn DynAny + Send>>();
+        assert_clone::<Box<dyn DynAny + Sync>>();
+        assert_clone::<Box<dyn DynAny + Send + Sync>>();
+    }
+}
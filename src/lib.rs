@@ -73,11 +73,14 @@
 
 extern crate alloc;
 
+pub use crate::any::DynAny;
 use crate::sealed::{Private, Sealed};
 
 #[macro_use]
 mod macros;
 
+mod any;
+
 #[doc(hidden)]
 pub mod private {
     pub use alloc::boxed::Box;
@@ -88,6 +91,8 @@ pub mod private {
 mod sealed {
     pub trait Sealed {}
     impl<T: Clone> Sealed for T {}
+    impl<T: Clone> Sealed for [T] {}
+    impl Sealed for str {}
     pub struct Private;
 }
 
@@ -101,6 +106,10 @@ pub trait DynClone: Sealed {
 }
 
 use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::String;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
 
 pub fn clone<T>(t: &T) -> T
 where
@@ -109,6 +118,11 @@ where
     unsafe { *Box::from_raw(<T as DynClone>::__clone_box(t, Private) as *mut T) }
 }
 
+// The returned box's fat pointer reuses `t`'s original length metadata and
+// overwrites only the data pointer with the freshly cloned allocation. For the
+// `[T]` and `str` impls this means the clone must allocate the same number of
+// elements as `t`; `clone_box` must not be called on a slice reference whose
+// length you intend to change.
 pub fn clone_box<T>(t: &T) -> Box<T>
 where
     T: ?Sized + DynClone,
@@ -122,6 +136,21 @@ where
     unsafe { Box::from_raw(fat_ptr as *mut T) }
 }
 
+pub fn clone_rc<T>(t: &T) -> Rc<T>
+where
+    T: ?Sized + DynClone,
+{
+    Rc::from(clone_box(t))
+}
+
+#[cfg(target_has_atomic = "ptr")]
+pub fn clone_arc<T>(t: &T) -> Arc<T>
+where
+    T: ?Sized + DynClone,
+{
+    Arc::from(clone_box(t))
+}
+
 impl<T> DynClone for T
 where
     T: Clone,
@@ -130,3 +159,18 @@ where
         Box::into_raw(Box::new(self.clone())) as *mut ()
     }
 }
+
+impl<T> DynClone for [T]
+where
+    T: Clone,
+{
+    fn __clone_box(&self, _: Private) -> *mut () {
+        Box::into_raw(self.to_vec().into_boxed_slice()) as *mut T as *mut ()
+    }
+}
+
+impl DynClone for str {
+    fn __clone_box(&self, _: Private) -> *mut () {
+        Box::into_raw(String::from(self).into_boxed_str()) as *mut u8 as *mut ()
+    }
+}
@@ -86,7 +86,25 @@ macro_rules! __internal_clone_trait_object {
 
     // The impl.
     (impl ($($generics:tt)*) ($($path:tt)*) ($($bound:tt)*)) => {
-        impl<'clone, $($generics)*> $crate::private_core::clone::Clone for $crate::private_alloc::boxed::Box<dyn $($path)* + 'clone> where $($bound)* {
+        impl<'clone, $($generics)*> $crate::private::Clone for $crate::private::Box<dyn $($path)* + 'clone> where $($bound)* {
+            fn clone(&self) -> Self {
+                $crate::clone_box(&**self)
+            }
+        }
+
+        impl<'clone, $($generics)*> $crate::private::Clone for $crate::private::Box<dyn $($path)* + $crate::private::Send + 'clone> where $($bound)* {
+            fn clone(&self) -> Self {
+                $crate::clone_box(&**self)
+            }
+        }
+
+        impl<'clone, $($generics)*> $crate::private::Clone for $crate::private::Box<dyn $($path)* + $crate::private::Sync + 'clone> where $($bound)* {
+            fn clone(&self) -> Self {
+                $crate::clone_box(&**self)
+            }
+        }
+
+        impl<'clone, $($generics)*> $crate::private::Clone for $crate::private::Box<dyn $($path)* + $crate::private::Send + $crate::private::Sync + 'clone> where $($bound)* {
             fn clone(&self) -> Self {
                 $crate::clone_box(&**self)
             }
@@ -110,6 +128,9 @@ mod tests {
         clone_trait_object!(Trait);
 
         assert_clone::<Box<dyn Trait>>();
+        assert_clone::<Box<dyn Trait + Send>>();
+        assert_clone::<Box<dyn Trait + Sync>>();
+        assert_clone::<Box<dyn Trait + Send + Sync>>();
     }
 
     #[test]
@@ -119,6 +140,8 @@ mod tests {
         clone_trait_object!(<T> Trait<T>);
 
         assert_clone::<Box<dyn Trait<u32>>>();
+        assert_clone::<Box<dyn Trait<u32> + Send>>();
+        assert_clone::<Box<dyn Trait<u32> + Send + Sync>>();
     }
 
     #[test]